@@ -63,7 +63,7 @@ async fn display_waiting_dots(rx: watch::Receiver<bool>, num_dots: usize) {
     let mut loading = true;
     while loading {
         for _ in 0..num_dots {
-            if !rx.borrow().clone() {
+            if !*rx.borrow() {
                 break;
             }
             print!(".");
@@ -76,7 +76,7 @@ async fn display_waiting_dots(rx: watch::Receiver<bool>, num_dots: usize) {
         }
         print!("\r");
         io::stdout().flush().unwrap();
-        loading = rx.borrow().clone();
+        loading = *rx.borrow();
     }
     print!("\r");
     for _ in 0..num_dots {
@@ -1,13 +1,25 @@
+use std::env;
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use openai_rust::chat;
+
+// src/openai.rs
+use crate::cloudllm::client_wrapper::{ClientWrapper, Message, Role};
+use crate::cloudllm::http_transport::HttpTransport;
+
 /// The `OpenAIClient` struct provides an implementation of the `ClientWrapper` trait for OpenAI's ChatGPT.
 /// This allows interactions with the OpenAI ChatGPT LLM REST API, abstracting the underlying details and 
 /// providing a consistent interface for sending and receiving messages.
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
 /// use cloudllm::clients::openai::OpenAIClient;
 /// use cloudllm::client_wrapper::{ClientWrapper, Message, Role};
 ///
+/// # async fn run() {
 /// let secret_key = "YOUR_OPENAI_SECRET_KEY";
 /// let model_name = "gpt-4";
 ///
@@ -17,31 +29,117 @@
 /// 
 /// let response = client.send_message(vec![Message { role: Role::System, content: system_prompt.to_string() }, msg]).await.unwrap();
 /// println!("Assistant: {}", response.content);
+/// # }
 /// ```
 ///
 /// # Note
 /// You will need to have the OpenAI API key and the desired model name (e.g., "gpt-4") to instantiate and use the client.
 ///
-use std::error::Error;
-
-use async_trait::async_trait;
-use openai_rust::chat;
-
-// src/openai.rs
-use crate::cloudllm::client_wrapper::{ClientWrapper, Message, Role};
-
+/// # Environment overrides
+/// Operators can swap the model of every `OpenAIClient` in a process without code changes by setting
+/// `CLOUDLLM_OPENAI_MODEL_OVERRIDE`. When it is set (and not blank), it replaces whatever model name
+/// was passed to the constructor, and the substitution is logged at `info` level.
+///
+/// # Connection reuse
+/// Clients send their requests through an `HttpTransport`. By default every `OpenAIClient` uses the
+/// process-wide `HttpTransport::shared()` pool; use `with_transport` to pass a different one.
 pub struct OpenAIClient {
-    client: openai_rust::Client,
+    secret_key: String,
     model: String,
+    transport: Arc<HttpTransport>,
 }
 
+/// Environment variable holding the OpenAI secret key, read by `OpenAIClient::new_from_env`.
+pub const SECRET_KEY_ENV_VAR: &str = "OPEN_AI_SECRET";
+/// Environment variable holding the model name, read by `OpenAIClient::new_from_env`.
+pub const MODEL_ENV_VAR: &str = "CLOUDLLM_OPENAI_MODEL";
+/// Environment variable that, when set, replaces the model name given to any `OpenAIClient` constructor.
+pub const MODEL_OVERRIDE_ENV_VAR: &str = "CLOUDLLM_OPENAI_MODEL_OVERRIDE";
+/// Model used by `OpenAIClient::new_from_env` when `CLOUDLLM_OPENAI_MODEL` is not set.
+pub const DEFAULT_MODEL: &str = "gpt-4o";
+
 impl OpenAIClient {
+    /// Creates a new client for the given model.
+    /// If `CLOUDLLM_OPENAI_MODEL_OVERRIDE` is set, its value is used instead of `model_name`.
+    /// Requests go through the process-wide `HttpTransport::shared()` connection pool.
     pub fn new(secret_key: &str, model_name: &str) -> Self {
         OpenAIClient {
-            client: openai_rust::Client::new(secret_key),
-            model: model_name.to_string(),
+            secret_key: secret_key.to_string(),
+            model: resolve_model_name(model_name),
+            transport: HttpTransport::shared(),
         }
     }
+
+    /// Sends this client's requests through `transport` instead of the process-wide default,
+    /// sharing its connection pool with every other client built with the same transport.
+    pub fn with_transport(mut self, transport: Arc<HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Creates a new client configured entirely from the environment.
+    /// The secret key is read from `OPEN_AI_SECRET` and the model from `CLOUDLLM_OPENAI_MODEL`
+    /// (falling back to `DEFAULT_MODEL` when unset or blank). `CLOUDLLM_OPENAI_MODEL_OVERRIDE`
+    /// still takes precedence.
+    ///
+    /// Returns an error naming `OPEN_AI_SECRET` if it is unset or blank.
+    ///
+    /// ```rust,no_run
+    /// use cloudllm::clients::openai::OpenAIClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenAIClient::new_from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_from_env() -> Result<Self, Box<dyn Error>> {
+        let secret_key = non_blank_env_var(SECRET_KEY_ENV_VAR)
+            .ok_or_else(|| format!("{} environment variable is not set", SECRET_KEY_ENV_VAR))?;
+        let model_name = non_blank_env_var(MODEL_ENV_VAR).unwrap_or_else(|| DEFAULT_MODEL.to_string());
+        Ok(OpenAIClient::new(&secret_key, &model_name))
+    }
+
+    /// Returns the name of the model this client sends requests to, after any environment override.
+    pub fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    /// Returns the transport this client sends its requests through.
+    pub fn transport(&self) -> &Arc<HttpTransport> {
+        &self.transport
+    }
+
+    /// Builds the `openai_rust` client for a request on top of this client's transport.
+    /// Cloning the `reqwest::Client` only bumps a reference count, so the connection pool is shared.
+    fn chat_client(&self) -> openai_rust::Client {
+        openai_rust::Client::new_with_client(&self.secret_key, self.transport.client().clone())
+    }
+}
+
+/// Applies `CLOUDLLM_OPENAI_MODEL_OVERRIDE` to the requested model name, if it is set.
+fn resolve_model_name(model_name: &str) -> String {
+    match non_blank_env_var(MODEL_OVERRIDE_ENV_VAR) {
+        Some(override_model) => {
+            if override_model != model_name {
+                log::info!(
+                    "{} is set, using OpenAI model '{}' instead of '{}'",
+                    MODEL_OVERRIDE_ENV_VAR,
+                    override_model,
+                    model_name
+                );
+            }
+            override_model
+        }
+        None => model_name.to_string(),
+    }
+}
+
+/// Returns the trimmed value of an environment variable, or `None` if it is unset or blank.
+fn non_blank_env_var(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
 }
 
 #[async_trait]
@@ -66,7 +164,7 @@ impl ClientWrapper for OpenAIClient {
             .collect();
 
         let args = chat::ChatArguments::new(&self.model, formatted_messages);
-        let res = self.client.create_chat(args).await?;
+        let res = self.chat_client().create_chat(args).await?;
         Ok(Message {
             role: Role::Assistant,
             content: res.choices[0].message.content.clone(),
@@ -74,3 +172,149 @@ impl ClientWrapper for OpenAIClient {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, MutexGuard};
+    use std::time::Duration;
+
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Serializes tests that touch the process environment.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Sets environment variables for the duration of a test and restores their previous values on drop.
+    struct EnvGuard {
+        saved: Vec<(&'static str, Option<String>)>,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl EnvGuard {
+        fn set(vars: &[(&'static str, Option<&str>)]) -> Self {
+            let lock = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            let saved = vars
+                .iter()
+                .map(|(name, value)| {
+                    let previous = env::var(name).ok();
+                    match value {
+                        Some(value) => env::set_var(name, value),
+                        None => env::remove_var(name),
+                    }
+                    (*name, previous)
+                })
+                .collect();
+            EnvGuard { saved, _lock: lock }
+        }
+    }
+
+    impl Drop for EnvGuard {
+        fn drop(&mut self) {
+            for (name, previous) in &self.saved {
+                match previous {
+                    Some(value) => env::set_var(name, value),
+                    None => env::remove_var(name),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn model_name_without_override() {
+        let _env = EnvGuard::set(&[(MODEL_OVERRIDE_ENV_VAR, None)]);
+        assert_eq!(OpenAIClient::new("test-key", "gpt-4").model_name(), "gpt-4");
+    }
+
+    #[test]
+    fn model_name_with_override() {
+        let _env = EnvGuard::set(&[(MODEL_OVERRIDE_ENV_VAR, Some("gpt-4.1-mini"))]);
+        assert_eq!(OpenAIClient::new("test-key", "gpt-4").model_name(), "gpt-4.1-mini");
+    }
+
+    #[test]
+    fn blank_override_is_ignored() {
+        for blank in &["", "   ", "\t\n"] {
+            let _env = EnvGuard::set(&[(MODEL_OVERRIDE_ENV_VAR, Some(blank))]);
+            assert_eq!(OpenAIClient::new("test-key", "gpt-4").model_name(), "gpt-4");
+        }
+    }
+
+    #[test]
+    fn padded_override_is_trimmed() {
+        let _env = EnvGuard::set(&[(MODEL_OVERRIDE_ENV_VAR, Some("  gpt-4.1-mini \n"))]);
+        assert_eq!(OpenAIClient::new("test-key", "gpt-4").model_name(), "gpt-4.1-mini");
+    }
+
+    #[test]
+    fn new_from_env_reads_model_and_falls_back_when_blank() {
+        let _env = EnvGuard::set(&[
+            (SECRET_KEY_ENV_VAR, Some("test-key")),
+            (MODEL_ENV_VAR, Some("gpt-4")),
+            (MODEL_OVERRIDE_ENV_VAR, None),
+        ]);
+        assert_eq!(OpenAIClient::new_from_env().unwrap().model_name(), "gpt-4");
+
+        env::set_var(MODEL_ENV_VAR, "  ");
+        assert_eq!(OpenAIClient::new_from_env().unwrap().model_name(), DEFAULT_MODEL);
+
+        env::remove_var(MODEL_ENV_VAR);
+        assert_eq!(OpenAIClient::new_from_env().unwrap().model_name(), DEFAULT_MODEL);
+    }
+
+    #[test]
+    fn new_from_env_error_names_missing_secret() {
+        for secret in &[None, Some(""), Some("   ")] {
+            let _env = EnvGuard::set(&[(SECRET_KEY_ENV_VAR, *secret)]);
+            let err = OpenAIClient::new_from_env().err().expect("missing secret should fail");
+            assert!(err.to_string().contains(SECRET_KEY_ENV_VAR));
+        }
+    }
+
+    #[test]
+    fn uses_shared_transport_by_default() {
+        let a = OpenAIClient::new("test-key", "gpt-4o");
+        let b = OpenAIClient::new("test-key", "gpt-4o");
+        assert!(Arc::ptr_eq(a.transport(), b.transport()));
+        assert!(Arc::ptr_eq(a.transport(), &HttpTransport::shared()));
+    }
+
+    #[test]
+    fn with_transport_replaces_shared_transport() {
+        let transport = Arc::new(HttpTransport::new());
+        let writer = OpenAIClient::new("test-key", "gpt-4o").with_transport(transport.clone());
+        let reviewer = OpenAIClient::new("test-key", "gpt-4o").with_transport(transport.clone());
+        assert!(Arc::ptr_eq(writer.transport(), &transport));
+        assert!(Arc::ptr_eq(reviewer.transport(), &transport));
+        assert!(!Arc::ptr_eq(writer.transport(), &HttpTransport::shared()));
+    }
+
+    #[tokio::test]
+    async fn send_message_goes_through_transport_client() {
+        // Route the transport's reqwest client through a local "proxy" so we can observe
+        // that the request really leaves through it, without touching the network.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        let http = reqwest::Client::builder()
+            .proxy(reqwest::Proxy::all(&proxy).unwrap())
+            .build()
+            .unwrap();
+        let client = OpenAIClient::new("test-key", "gpt-4o")
+            .with_transport(Arc::new(HttpTransport::from_client(http)));
+
+        let proxy_side = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 1024];
+            let n = socket.read(&mut buf).await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+
+        let message = Message { role: Role::User, content: "Hello".to_string() };
+        assert!(client.send_message(vec![message]).await.is_err());
+        let request = tokio::time::timeout(Duration::from_secs(5), proxy_side)
+            .await
+            .expect("request never reached the transport's client")
+            .unwrap();
+        assert!(request.starts_with("CONNECT api.openai.com:443"));
+    }
+}
@@ -0,0 +1,65 @@
+//! The `http_transport` module holds the HTTP connection pool shared by client wrappers.
+//!
+//! Every `reqwest::Client` owns its own connection pool. If each client wrapper built its own,
+//! an application running several sessions against the same provider would keep one pool per
+//! client and redo the TCP and TLS handshakes for each of them. `HttpTransport` wraps a single
+//! `reqwest::Client` so that it can be handed to any number of client wrappers, which then reuse
+//! the same warm connections.
+//!
+//! When no transport is given, clients use `HttpTransport::shared()`, a process-wide instance
+//! created lazily on first use.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use cloudllm::clients::openai::OpenAIClient;
+//! use cloudllm::HttpTransport;
+//!
+//! let transport = Arc::new(HttpTransport::new());
+//! let writer = OpenAIClient::new("YOUR_OPENAI_SECRET_KEY", "gpt-4o").with_transport(transport.clone());
+//! let reviewer = OpenAIClient::new("YOUR_OPENAI_SECRET_KEY", "gpt-4o").with_transport(transport);
+//! ```
+
+use std::sync::{Arc, OnceLock};
+
+static SHARED_TRANSPORT: OnceLock<Arc<HttpTransport>> = OnceLock::new();
+
+/// A reusable HTTP transport (connection pool) for client wrappers.
+pub struct HttpTransport {
+    /// The underlying HTTP client. Cloning it shares the same connection pool.
+    client: reqwest::Client,
+}
+
+impl HttpTransport {
+    /// Creates a new transport backed by a default `reqwest::Client`.
+    pub fn new() -> Self {
+        HttpTransport {
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Creates a transport from an existing `reqwest::Client`, e.g. one configured with
+    /// custom timeouts or a proxy.
+    pub fn from_client(client: reqwest::Client) -> Self {
+        HttpTransport { client }
+    }
+
+    /// Returns the process-wide transport used by clients that weren't given one explicitly.
+    pub fn shared() -> Arc<HttpTransport> {
+        SHARED_TRANSPORT
+            .get_or_init(|| Arc::new(HttpTransport::new()))
+            .clone()
+    }
+
+    /// Returns the underlying `reqwest::Client`.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        HttpTransport::new()
+    }
+}
@@ -36,6 +36,8 @@
 //! and the maximum number of tokens allowed in the conversation (including the system prompt).
 //!
 //! ```rust
+//! # use cloudllm::clients::openai::OpenAIClient;
+//! # let openai_client = OpenAIClient::new("YOUR_OPENAI_SECRET_KEY", "gpt-4");
 //! use cloudllm::LLMSession;
 //! let system_prompt = "You are an AI assistant.";
 //! let max_tokens = 8000; // Adjust based on the model's token limit
@@ -48,12 +50,18 @@
 //! the conversation history to ensure it doesn't exceed the token limit, trimming older messages as needed.
 //! This ensures a consistent and coherent interaction over multiple message exchanges.
 //!
-//! ```rust
+//! ```rust,no_run
 //! use cloudllm::client_wrapper::Role;
+//! # use cloudllm::clients::openai::OpenAIClient;
+//! # use cloudllm::LLMSession;
+//! # let openai_client = OpenAIClient::new("YOUR_OPENAI_SECRET_KEY", "gpt-4");
+//! # let mut session = LLMSession::new(openai_client, "You are an AI assistant.".to_string(), 8000);
+//! # async {
 //!
 //! let user_message = "Hello, World!";
 //! let response = session.send_message(Role::User, user_message.to_string()).await.unwrap();
 //! println!("Assistant: {}", response.content);
+//! # };
 //! ```
 //!
 //! The session's history grows with each interaction but remains within the token constraints of the LLM.
@@ -64,6 +72,10 @@
 //! You can update the system prompt during the session to change the conversation's context.
 //!
 //! ```rust
+//! # use cloudllm::clients::openai::OpenAIClient;
+//! # use cloudllm::LLMSession;
+//! # let openai_client = OpenAIClient::new("YOUR_OPENAI_SECRET_KEY", "gpt-4");
+//! # let mut session = LLMSession::new(openai_client, "You are an AI assistant.".to_string(), 8000);
 //! let new_system_prompt = "You are a helpful assistant specialized in astronomy.";
 //! session.set_system_prompt(new_system_prompt.to_string());
 //! ```
@@ -74,13 +86,20 @@
 //! when necessary. If the system prompt and a single message exceed `max_tokens`, you may need to reduce
 //! their length or increase `max_tokens` (within the model's limitations).
 //!
-//! ```rust
+//! ```rust,no_run
+//! # use cloudllm::client_wrapper::Role;
+//! # use cloudllm::clients::openai::OpenAIClient;
+//! # use cloudllm::LLMSession;
+//! # let openai_client = OpenAIClient::new("YOUR_OPENAI_SECRET_KEY", "gpt-4");
+//! # let mut session = LLMSession::new(openai_client, "You are an AI assistant.".to_string(), 8000);
+//! # async {
 //! // Attempt to send a very long message
 //! let long_message = "A".repeat(10000); // A long message exceeding token limits
 //! match session.send_message(Role::User, long_message).await {
 //!     Ok(response) => println!("Assistant: {}", response.content),
 //!     Err(e) => eprintln!("Error: {}", e),
 //! }
+//! # };
 //! ```
 //!
 //! ## Notes
@@ -1,6 +1,7 @@
 // src/cloudllm/mod.rs
 
 pub mod client_wrapper;
+pub mod http_transport;
 pub mod llm_session;
 pub mod clients;
 
@@ -45,6 +45,7 @@ pub mod cloudllm;
 // Re-exporting key items for easier external access.
 pub use cloudllm::client_wrapper;
 pub use cloudllm::client_wrapper::{ClientWrapper, Message, Role};
+pub use cloudllm::http_transport::HttpTransport;
 pub use cloudllm::llm_session::LLMSession;
 // If you wish, you can also re-export specific clients or functionalities from the `clients` submodule:
 // pub use cloudllm::clients::openai;